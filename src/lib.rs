@@ -10,8 +10,16 @@
 //! The Eigenlayer implementation provides:
 //! - Validator coordination across NRSH, ELXR, and IMRT chains
 //! - Restaking mechanism for enhanced security
+//! - Slashing of restaked collateral on failed verification or misbehavior
+//! - Era-based reward accrual for restakers, proportional to stake-time
 //! - ActorX fill and kill operations with quantum keys
-//! - Multi-level error correction
+//! - Pluggable, per-layer error correction applied to the stored validator,
+//!   restake, and ActorX operation payloads
+//!
+//! The pallet is instantiable: the runtime mounts one independent instance per
+//! chain (NRSH, ELXR, IMRT), each with its own validator set, restakes, and
+//! `MinRestakeAmount`/`RestakePeriod`, so membership in one chain's validator
+//! set carries no weight in another's.
 //!
 //! ## Interface
 //!
@@ -20,13 +28,28 @@
 //! * `restake` - Restake tokens for enhanced security
 //! * `execute_actorx` - Execute ActorX fill and kill operations
 //! * `verify_validator` - Verify a validator's quantum credentials
+//! * `unbond` - Begin unbonding a portion of a validator's restake
+//! * `withdraw_unbonded` - Withdraw restake whose unbonding period has elapsed
+//! * `claim_rewards` - Claim a validator's pro-rata restake reward for a past era
 //!
 //! ### Public Functions
 //! * `get_validator_set` - Get the current active validator set
+//! * `get_validator_info` - Get a validator's decoded registration info
 //! * `get_restake_info` - Get information about a validator's restaked tokens
+//! * `get_actorx_operation` - Get a recorded ActorX operation by ID
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// Brought into scope here, at crate-root, for `ReedSolomon<K, M>` below:
+// `#[frame_support::pallet]` only brings `PhantomData` into the `pallet`
+// module it expands, not its parent.
+use core::marker::PhantomData;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
@@ -35,26 +58,76 @@ pub mod pallet {
         traits::{Currency, Get, OnUnbalanced, ReservableCurrency},
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{Convert, Zero};
+    use sp_runtime::{traits::{Convert, Zero}, Perbill};
     use sp_std::prelude::*;
 
+    /// Entries queued for bulk quantum-proof verification. Accumulated
+    /// across every `Bulk`-strategy call in a block and settled together,
+    /// once, at `on_finalize`.
+    pub(super) type ProofBatchEntry<T> = (<T as frame_system::Config>::AccountId, Vec<u8>, ProofBatchKind);
+
     // Define the pallet configuration trait
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>: frame_system::Config {
         /// The overarching event type
-        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-        
+        type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+
         /// The currency mechanism
         type Currency: ReservableCurrency<Self::AccountId>;
-        
+
         /// The period duration for restaking
         #[pallet::constant]
         type RestakePeriod: Get<Self::BlockNumber>;
-        
+
         /// Minimum amount that can be restaked
         #[pallet::constant]
-        type MinRestakeAmount: Get<BalanceOf<Self>>;
-        
+        type MinRestakeAmount: Get<BalanceOf<Self, I>>;
+
+        /// Handler for the imbalance produced by slashing a validator's restake
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+
+        /// Fraction of a validator's restake slashed on a failed verification
+        #[pallet::constant]
+        type SlashFractionOnVerificationFailure: Get<Perbill>;
+
+        /// Fraction of a validator's restake slashed on a rejected ActorX operation
+        #[pallet::constant]
+        type SlashFractionOnInvalidOperation: Get<Perbill>;
+
+        /// Maximum number of unlocking chunks an account may have queued at once
+        #[pallet::constant]
+        type MaxUnlockingChunks: Get<u32>;
+
+        /// Total reward pot issued at the end of each era
+        #[pallet::constant]
+        type RewardPerEra: Get<BalanceOf<Self, I>>;
+
+        /// Converts a number of blocks into the `Balance` domain, for
+        /// weighting restakes by how long they have been active within an era
+        type BlockNumberToBalance: Convert<Self::BlockNumber, BalanceOf<Self, I>>;
+
+        /// Handler for reward pot currency that goes unclaimed: a pot with no
+        /// active validators to pay, or a past era's rewards once they fall
+        /// outside `HistoryDepth`
+        type RewardSource: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+
+        /// Number of past eras for which accrued rewards remain claimable
+        #[pallet::constant]
+        type HistoryDepth: Get<u32>;
+
+        /// Error-correction layer applied first when protecting a stored
+        /// payload, and unwound last when reading it back. Defaults to
+        /// [`ReedSolomon`] in most runtimes.
+        type ClassicalEcc: ErrorCorrection;
+
+        /// Error-correction layer applied over the bridge transport that
+        /// relays this pallet's state to NRSH, ELXR, and IMRT.
+        type BridgeEcc: ErrorCorrection;
+
+        /// Error-correction layer applied over quantum-channel noise,
+        /// closest to the validator's quantum credentials.
+        type QuantumEcc: ErrorCorrection;
+
         /// Weight information for extrinsics
         type WeightInfo: WeightInfo;
     }
@@ -62,46 +135,118 @@ pub mod pallet {
     // Define the pallet storage items
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
-    pub struct Pallet<T>(_);
-    
-    // Validator registry
+    pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+    // Validator registry. Holds the SCALE-encoded `ValidatorInfo`, protected
+    // by `ClassicalEcc` -> `BridgeEcc` -> `QuantumEcc`; read and written
+    // exclusively through `Pallet::load_validator`/`Pallet::store_validator`.
     #[pallet::storage]
     #[pallet::getter(fn validators)]
-    pub type Validators<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ValidatorInfo<T>>;
-    
+    pub type Validators<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, T::AccountId, Vec<u8>>;
+
     // Active validator set
     #[pallet::storage]
     #[pallet::getter(fn active_validators)]
-    pub type ActiveValidators<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
-    
-    // Restake information
+    pub type ActiveValidators<T: Config<I>, I: 'static = ()> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+    // Restake information. Holds the SCALE-encoded, error-correction
+    // protected `RestakeInfo`; read and written exclusively through
+    // `Pallet::load_restake`/`Pallet::store_restake`.
     #[pallet::storage]
     #[pallet::getter(fn restakes)]
-    pub type Restakes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, RestakeInfo<T>>;
-    
-    // ActorX operations registry
+    pub type Restakes<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, T::AccountId, Vec<u8>>;
+
+    // ActorX operations registry. Holds the SCALE-encoded, error-correction
+    // protected `ActorXOperation`; written exclusively through
+    // `Pallet::store_actorx_operation`.
     #[pallet::storage]
     #[pallet::getter(fn actorx_operations)]
-    pub type ActorXOperations<T: Config> = StorageMap<_, Blake2_128Concat, OperationId, ActorXOperation<T>>;
+    pub type ActorXOperations<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, OperationId, Vec<u8>>;
+
+    // Quantum proofs queued for bulk verification in the current block
+    #[pallet::storage]
+    #[pallet::getter(fn pending_proofs)]
+    pub type PendingProofs<T: Config<I>, I: 'static = ()> = StorageValue<_, Vec<ProofBatchEntry<T>>, ValueQuery>;
+
+    // Slashing history per validator
+    #[pallet::storage]
+    #[pallet::getter(fn slash_records)]
+    pub type SlashRecords<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<SlashRecord<T::AccountId, BalanceOf<T, I>, T::BlockNumber>>,
+        ValueQuery,
+    >;
+
+    // Restake amounts in the process of unbonding, per account
+    #[pallet::storage]
+    #[pallet::getter(fn unlocking)]
+    pub type Unlocking<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<UnlockChunk<BalanceOf<T, I>, T::BlockNumber>>,
+        ValueQuery,
+    >;
+
+    // The current era index
+    #[pallet::storage]
+    #[pallet::getter(fn current_era)]
+    pub type CurrentEra<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+    // Block number at which the current era began
+    #[pallet::storage]
+    #[pallet::getter(fn current_era_start)]
+    pub type CurrentEraStart<T: Config<I>, I: 'static = ()> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+    // Restake-weighted reward points snapshotted for each validator at era rotation
+    #[pallet::storage]
+    #[pallet::getter(fn era_reward_points)]
+    pub type EraRewardPoints<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, u32, Vec<(T::AccountId, BalanceOf<T, I>)>, ValueQuery>;
+
+    // Claimable reward balances, keyed by era then validator
+    #[pallet::storage]
+    #[pallet::getter(fn pending_rewards)]
+    pub type PendingRewards<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T, I>,
+    >;
 
     // Define the pallet events
     #[pallet::event]
     #[pallet::metadata(T::AccountId = "AccountId")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// Validator registered
         ValidatorRegistered(T::AccountId, QuantumKeyHash),
         /// Tokens restaked
-        TokensRestaked(T::AccountId, BalanceOf<T>, T::BlockNumber),
+        TokensRestaked(T::AccountId, BalanceOf<T, I>, T::BlockNumber),
         /// ActorX operation executed
         ActorXExecuted(T::AccountId, OperationId, OperationType),
         /// Validator verified
         ValidatorVerified(T::AccountId, bool),
+        /// Validator slashed for a verification failure or misbehavior
+        ValidatorSlashed(T::AccountId, BalanceOf<T, I>, SlashReason),
+        /// Restake moved into an unlocking chunk, to mature at the given block
+        Unbonded(T::AccountId, BalanceOf<T, I>, T::BlockNumber),
+        /// Matured unlocking chunks unreserved back to the account
+        Withdrawn(T::AccountId, BalanceOf<T, I>),
+        /// A new era began; the previous era's reward pot was split pro-rata
+        /// among the active validator set
+        EraRotated(u32, T::BlockNumber),
+        /// A validator claimed its pro-rata reward for a past era
+        RewardClaimed(T::AccountId, u32, BalanceOf<T, I>),
     }
 
     // Define the pallet errors
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// Validator already registered
         ValidatorAlreadyRegistered,
         /// Validator not registered
@@ -114,46 +259,73 @@ pub mod pallet {
         ActorXOperationFailed,
         /// Quantum verification failed
         QuantumVerificationFailed,
-        /// Error correction failed
-        ErrorCorrectionFailed,
+        /// The `ClassicalEcc` layer failed to encode or decode a payload
+        ClassicalErrorCorrectionFailed,
+        /// The `BridgeEcc` layer failed to encode or decode a payload
+        BridgeErrorCorrectionFailed,
+        /// The `QuantumEcc` layer failed to encode or decode a payload
+        QuantumErrorCorrectionFailed,
+        /// A payload decoded through every error-correction layer, but did
+        /// not SCALE-decode back into the expected type
+        PayloadDecodeFailed,
         /// Invalid operation type
         InvalidOperationType,
+        /// Account has no active restake to unbond
+        NoActiveRestake,
+        /// Requested unbond amount exceeds the account's active restake
+        UnbondAmountExceedsRestake,
+        /// Account has reached `MaxUnlockingChunks` queued unlocking chunks
+        TooManyUnlockingChunks,
+        /// No unlocking chunks have matured yet
+        NothingToWithdraw,
+        /// No claimable reward was recorded for the caller in the given era
+        NoRewardsForEra,
+        /// The requested era's rewards have aged out of `HistoryDepth`
+        EraRewardsExpired,
     }
 
     // Implement the dispatchable functions
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// Register a validator with the Eigenlayer
         #[pallet::weight(T::WeightInfo::register_validator())]
         pub fn register_validator(
             origin: OriginFor<T>,
             quantum_key: QuantumKey,
+            strategy: VerifyStrategy,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            
+
             // Ensure validator is not already registered
-            ensure!(!<Validators<T>>::contains_key(&who), Error::<T>::ValidatorAlreadyRegistered);
-            
-            // Apply quantum verification
-            Self::verify_quantum_key(&quantum_key)
-                .map_err(|_| Error::<T>::QuantumVerificationFailed)?;
-            
-            // Apply error correction
-            Self::apply_error_correction()?;
-            
+            ensure!(!<Validators<T, I>>::contains_key(&who), Error::<T, I>::ValidatorAlreadyRegistered);
+
+            // `Individual` verifies the key right away and aborts
+            // registration on failure. `Bulk` instead queues it into
+            // `PendingProofs`: registration proceeds optimistically, and
+            // `on_finalize` undoes it if the key turns out to be invalid
+            // once every entry queued this block is settled together.
+            if let VerifyStrategy::Individual = strategy {
+                Self::verify_quantum_key(&quantum_key)
+                    .map_err(|_| Error::<T, I>::QuantumVerificationFailed)?;
+            }
+
             // Calculate key hash
             let key_hash = Self::hash_quantum_key(&quantum_key);
-            
+
             // Register validator
-            let validator_info = ValidatorInfo::<T> {
+            let validator_info = ValidatorInfo {
                 account_id: who.clone(),
                 quantum_key_hash: key_hash.clone(),
                 registered_at: <frame_system::Pallet<T>>::block_number(),
                 status: ValidatorStatus::Registered,
             };
-            
-            <Validators<T>>::insert(&who, validator_info);
-            
+
+            Self::store_validator(&who, &validator_info)?;
+
+            if let VerifyStrategy::Bulk = strategy {
+                <PendingProofs<T, I>>::append((who.clone(), quantum_key, ProofBatchKind::ValidatorKey));
+            }
+
             Self::deposit_event(Event::ValidatorRegistered(who, key_hash));
             Ok(().into())
         }
@@ -162,40 +334,37 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::restake())]
         pub fn restake(
             origin: OriginFor<T>,
-            amount: BalanceOf<T>,
+            amount: BalanceOf<T, I>,
             duration: T::BlockNumber,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            
+
             // Ensure validator is registered
-            ensure!(<Validators<T>>::contains_key(&who), Error::<T>::ValidatorNotRegistered);
-            
+            ensure!(<Validators<T, I>>::contains_key(&who), Error::<T, I>::ValidatorNotRegistered);
+
             // Ensure minimum restake amount
-            ensure!(amount >= T::MinRestakeAmount::get(), Error::<T>::MinRestakeNotMet);
-            
+            ensure!(amount >= T::MinRestakeAmount::get(), Error::<T, I>::MinRestakeNotMet);
+
             // Ensure sufficient balance
-            ensure!(T::Currency::can_reserve(&who, amount), Error::<T>::InsufficientBalance);
-            
-            // Apply error correction
-            Self::apply_error_correction()?;
-            
+            ensure!(T::Currency::can_reserve(&who, amount), Error::<T, I>::InsufficientBalance);
+
             // Reserve the tokens
             T::Currency::reserve(&who, amount)?;
-            
+
             // Calculate unlock block
             let current_block = <frame_system::Pallet<T>>::block_number();
             let unlock_block = current_block.saturating_add(duration);
-            
+
             // Update restake info
-            let restake_info = RestakeInfo::<T> {
+            let restake_info = RestakeInfo {
                 account_id: who.clone(),
                 amount,
                 start_block: current_block,
                 unlock_block,
             };
-            
-            <Restakes<T>>::insert(&who, restake_info);
-            
+
+            Self::store_restake(&who, &restake_info)?;
+
             Self::deposit_event(Event::TokensRestaked(who, amount, unlock_block));
             Ok(().into())
         }
@@ -207,30 +376,43 @@ pub mod pallet {
             operation_type: OperationType,
             target: T::AccountId,
             quantum_proof: QuantumProof,
+            strategy: VerifyStrategy,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            
+
             // Ensure validator is registered
-            ensure!(<Validators<T>>::contains_key(&who), Error::<T>::ValidatorNotRegistered);
-            
+            ensure!(<Validators<T, I>>::contains_key(&who), Error::<T, I>::ValidatorNotRegistered);
+
             // Verify operation type
             ensure!(
                 operation_type == OperationType::Fill || operation_type == OperationType::Kill,
-                Error::<T>::InvalidOperationType
+                Error::<T, I>::InvalidOperationType
             );
-            
-            // Apply quantum verification
-            Self::verify_quantum_proof(&quantum_proof)
-                .map_err(|_| Error::<T>::QuantumVerificationFailed)?;
-            
-            // Apply error correction
-            Self::apply_error_correction()?;
-            
+
+            // `Individual` verifies the proof right away: a rejected proof
+            // slashes the executor's restake, drops it from the active set,
+            // and the operation is never recorded. `Bulk` instead records
+            // the operation optimistically and queues the proof into
+            // `PendingProofs`; `on_finalize` applies the same slash-and-drop
+            // (and removes the operation) if the proof turns out to be
+            // invalid once the block's queued batch is settled.
+            if let VerifyStrategy::Individual = strategy {
+                if Self::verify_quantum_proof(&quantum_proof).is_err() {
+                    Self::slash(
+                        &who,
+                        T::SlashFractionOnInvalidOperation::get(),
+                        SlashReason::InvalidOperation,
+                    )?;
+                    Self::remove_active_validator(&who);
+                    return Err(Error::<T, I>::QuantumVerificationFailed.into());
+                }
+            }
+
             // Generate operation ID
             let operation_id = Self::next_operation_id();
-            
+
             // Register operation
-            let operation = ActorXOperation::<T> {
+            let operation = ActorXOperation {
                 id: operation_id,
                 operation_type: operation_type.clone(),
                 executor: who.clone(),
@@ -238,9 +420,17 @@ pub mod pallet {
                 executed_at: <frame_system::Pallet<T>>::block_number(),
                 proof_hash: Self::hash_quantum_proof(&quantum_proof),
             };
-            
-            <ActorXOperations<T>>::insert(operation_id, operation);
-            
+
+            Self::store_actorx_operation(operation_id, &operation)?;
+
+            if let VerifyStrategy::Bulk = strategy {
+                <PendingProofs<T, I>>::append((
+                    who.clone(),
+                    quantum_proof,
+                    ProofBatchKind::ActorXProof(operation_id),
+                ));
+            }
+
             Self::deposit_event(Event::ActorXExecuted(who, operation_id, operation_type));
             Ok(().into())
         }
@@ -252,106 +442,936 @@ pub mod pallet {
             validator: T::AccountId,
         ) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
-            
+
             // Ensure validator is registered
-            ensure!(<Validators<T>>::contains_key(&validator), Error::<T>::ValidatorNotRegistered);
-            
+            ensure!(<Validators<T, I>>::contains_key(&validator), Error::<T, I>::ValidatorNotRegistered);
+
             // Get validator info
-            let mut validator_info = <Validators<T>>::get(&validator).unwrap();
-            
-            // Apply error correction
-            Self::apply_error_correction()?;
-            
+            let mut validator_info = Self::load_validator(&validator)?
+                .ok_or(Error::<T, I>::ValidatorNotRegistered)?;
+
             // Perform verification (complex quantum logic would be here)
             let verification_result = true; // Placeholder
-            
+
             // Update validator status
             if verification_result {
                 validator_info.status = ValidatorStatus::Verified;
-                
+
                 // Add to active validators if not already there
-                let mut active = <ActiveValidators<T>>::get();
+                let mut active = <ActiveValidators<T, I>>::get();
                 if !active.contains(&validator) {
                     active.push(validator.clone());
-                    <ActiveValidators<T>>::put(active);
+                    <ActiveValidators<T, I>>::put(active);
                 }
             } else {
                 validator_info.status = ValidatorStatus::Failed;
+
+                Self::slash(
+                    &validator,
+                    T::SlashFractionOnVerificationFailure::get(),
+                    SlashReason::VerificationFailure,
+                )?;
+                Self::remove_active_validator(&validator);
             }
-            
-            <Validators<T>>::insert(&validator, validator_info);
-            
+
+            Self::store_validator(&validator, &validator_info)?;
+
             Self::deposit_event(Event::ValidatorVerified(validator, verification_result));
             Ok(().into())
         }
+
+        /// Begin unbonding `amount` of the caller's active restake. The
+        /// amount is moved into an unlocking chunk that matures (becomes
+        /// withdrawable) after `RestakePeriod` blocks.
+        #[pallet::weight(T::WeightInfo::unbond())]
+        pub fn unbond(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let mut restake = Self::load_restake(&who)?.ok_or(Error::<T, I>::NoActiveRestake)?;
+            ensure!(amount <= restake.amount, Error::<T, I>::UnbondAmountExceedsRestake);
+
+            let mut chunks = <Unlocking<T, I>>::get(&who);
+            ensure!(
+                (chunks.len() as u32) < T::MaxUnlockingChunks::get(),
+                Error::<T, I>::TooManyUnlockingChunks
+            );
+
+            let unlock_block = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::RestakePeriod::get());
+
+            restake.amount = restake.amount.saturating_sub(amount);
+            chunks.push(UnlockChunk { value: amount, unlock_block });
+            <Unlocking<T, I>>::insert(&who, chunks);
+
+            if restake.amount.is_zero() {
+                <Restakes<T, I>>::remove(&who);
+            } else {
+                Self::store_restake(&who, &restake)?;
+            }
+
+            Self::deposit_event(Event::Unbonded(who, amount, unlock_block));
+            Ok(().into())
+        }
+
+        /// Unreserve every unlocking chunk of the caller's that has matured,
+        /// dropping the validator from the active set if its remaining
+        /// active restake falls below `MinRestakeAmount`.
+        #[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+
+            let chunks = <Unlocking<T, I>>::get(&who);
+            let (matured, remaining): (Vec<_>, Vec<_>) =
+                chunks.into_iter().partition(|chunk| chunk.unlock_block <= current_block);
+            ensure!(!matured.is_empty(), Error::<T, I>::NothingToWithdraw);
+
+            let total = matured
+                .iter()
+                .fold(Zero::zero(), |acc: BalanceOf<T, I>, chunk| acc.saturating_add(chunk.value));
+            T::Currency::unreserve(&who, total);
+
+            if remaining.is_empty() {
+                <Unlocking<T, I>>::remove(&who);
+            } else {
+                <Unlocking<T, I>>::insert(&who, remaining);
+            }
+
+            let active_amount = Self::load_restake(&who)?.map(|r| r.amount).unwrap_or_else(Zero::zero);
+            if active_amount < T::MinRestakeAmount::get() {
+                Self::remove_active_validator(&who);
+            }
+
+            Self::deposit_event(Event::Withdrawn(who, total));
+            Ok(().into())
+        }
+
+        /// Claim the caller's pro-rata restake reward for a past `era`.
+        /// Removes the pending entry on success, so a given era can only
+        /// ever be claimed once.
+        #[pallet::weight(T::WeightInfo::claim_rewards())]
+        pub fn claim_rewards(origin: OriginFor<T>, era: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let current_era = <CurrentEra<T, I>>::get();
+            ensure!(
+                era.saturating_add(T::HistoryDepth::get()) >= current_era,
+                Error::<T, I>::EraRewardsExpired
+            );
+
+            let amount = <PendingRewards<T, I>>::take(era, &who).ok_or(Error::<T, I>::NoRewardsForEra)?;
+            T::Currency::deposit_creating(&who, amount);
+
+            Self::deposit_event(Event::RewardClaimed(who, era, amount));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        /// Advance the era once `RestakePeriod` blocks have elapsed since the
+        /// current era began, snapshotting reward points for the era just
+        /// finished and sweeping any reward left over from eras that have
+        /// since aged out of `HistoryDepth`.
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let era_start = <CurrentEraStart<T, I>>::get();
+            if now.saturating_sub(era_start) < T::RestakePeriod::get() {
+                return 0;
+            }
+
+            Self::rotate_era(now);
+            0
+        }
+
+        /// The one genuine batch point for `PendingProofs`: every entry
+        /// queued by a `Bulk`-strategy call so far this block is settled
+        /// together here, once, rather than as each call is made.
+        fn on_finalize(_now: T::BlockNumber) {
+            Self::settle_pending_proofs();
+        }
     }
 
     // Helper functions
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         // Generate a unique operation ID
         fn next_operation_id() -> OperationId {
             // Implementation
             0 // Placeholder
         }
-        
+
         // Hash a quantum key
         fn hash_quantum_key(key: &QuantumKey) -> QuantumKeyHash {
             // Implementation
             vec![0, 1, 2, 3] // Placeholder
         }
-        
+
         // Hash a quantum proof
         fn hash_quantum_proof(proof: &QuantumProof) -> QuantumProofHash {
             // Implementation
             vec![0, 1, 2, 3] // Placeholder
         }
-        
+
         // Verify a quantum key
         fn verify_quantum_key(key: &QuantumKey) -> Result<(), ()> {
             // Implementation
             Ok(())
         }
-        
+
         // Verify a quantum proof
         fn verify_quantum_proof(proof: &QuantumProof) -> Result<(), ()> {
             // Implementation
             Ok(())
         }
-        
-        // Apply comprehensive error correction
-        fn apply_error_correction() -> Result<(), Error<T>> {
-            // Apply classical error correction
-            Self::apply_classical_error_correction()
-                .map_err(|_| Error::<T>::ErrorCorrectionFailed)?;
-            
-            // Apply bridge error correction
-            Self::apply_bridge_error_correction()
-                .map_err(|_| Error::<T>::ErrorCorrectionFailed)?;
-            
-            // Apply quantum error correction
-            Self::apply_quantum_error_correction()
-                .map_err(|_| Error::<T>::ErrorCorrectionFailed)?;
-            
+
+        /// Settle every entry queued in `PendingProofs` this block with a
+        /// single random-coefficient aggregate check, then drain the queue.
+        /// On aggregate failure, fall back to verifying each entry
+        /// individually and undo only the optimistic writes made for the
+        /// entries that actually fail, so a lone bad proof doesn't cost the
+        /// rest of the batch.
+        fn settle_pending_proofs() {
+            let entries = <PendingProofs<T, I>>::take();
+            if entries.is_empty() {
+                return;
+            }
+
+            // `block_hash(current_number)` is unset until the block
+            // finalizes, so `parent_hash` is the freshest hash available
+            // here; it is just as unpredictable to whoever crafted the
+            // proofs queued earlier in this block.
+            let parent_hash = <frame_system::Pallet<T>>::parent_hash();
+            let coefficients: Vec<u64> = (0..entries.len())
+                .map(|index| Self::random_coefficient(&parent_hash, index))
+                .collect();
+
+            if Self::check_aggregate(&entries, &coefficients) {
+                return;
+            }
+
+            // The aggregate check failed, which only tells us *some* entry
+            // in the batch is bad. Fall back to per-entry verification to
+            // pinpoint and undo exactly the offending entries.
+            for (who, payload, kind) in entries.iter() {
+                let valid = match kind {
+                    ProofBatchKind::ValidatorKey => Self::verify_quantum_key(payload).is_ok(),
+                    ProofBatchKind::ActorXProof(_) => Self::verify_quantum_proof(payload).is_ok(),
+                };
+                if valid {
+                    continue;
+                }
+
+                match kind {
+                    ProofBatchKind::ValidatorKey => {
+                        <Validators<T, I>>::remove(who);
+                    }
+                    ProofBatchKind::ActorXProof(operation_id) => {
+                        <ActorXOperations<T, I>>::remove(operation_id);
+                        let _ = Self::slash(
+                            who,
+                            T::SlashFractionOnInvalidOperation::get(),
+                            SlashReason::InvalidOperation,
+                        );
+                        Self::remove_active_validator(who);
+                    }
+                }
+            }
+        }
+
+        /// Draw a distinct random scalar `r_i` for batch entry `index`,
+        /// deterministically seeded from the block hash and the entry's
+        /// position so every node derives the same coefficients. These
+        /// coefficients defeat rogue-aggregation, where a crafted pair of
+        /// invalid proofs would otherwise cancel each other out.
+        fn random_coefficient(block_hash: &T::Hash, index: usize) -> u64 {
+            let mut seed = block_hash.as_ref().to_vec();
+            seed.extend_from_slice(&(index as u64).to_le_bytes());
+            u64::from_le_bytes(sp_io::hashing::blake2_64(&seed))
+        }
+
+        /// Fold every `(payload, r_i)` pair into one combined buffer: each
+        /// payload is scaled byte-for-byte (wrapping integer multiplication)
+        /// by the low byte of its own `r_i` and XORed into the accumulator. A crafted pair of invalid proofs that would
+        /// cancel out under equal weighting won't cancel out here, since
+        /// the attacker would have to predict every `r_i` before submitting
+        /// them.
+        fn combine_payloads<'a>(payloads: impl Iterator<Item = (&'a [u8], u64)>) -> Vec<u8> {
+            payloads.fold(Vec::new(), |mut combined, (payload, r_i)| {
+                if combined.len() < payload.len() {
+                    combined.resize(payload.len(), 0);
+                }
+                let scale = r_i as u8;
+                for (out, byte) in combined.iter_mut().zip(payload.iter()) {
+                    *out ^= byte.wrapping_mul(scale);
+                }
+                combined
+            })
+        }
+
+        /// Verify a `Bulk` batch with one combined check per entry kind
+        /// instead of one check per entry: every `ValidatorKey` payload is
+        /// folded (weighted by its `r_i`) into a single buffer and checked
+        /// with one `verify_quantum_key` call, and likewise once for every
+        /// `ActorXProof` payload via `verify_quantum_proof` - so a clean
+        /// batch costs at most two verification calls, not `entries.len()`,
+        /// unlike `Individual` which always pays one call per entry. Only
+        /// when this combined check disagrees does `settle_pending_proofs`
+        /// fall back to the per-entry loop to find out which entry is
+        /// actually bad.
+        fn check_aggregate(entries: &[ProofBatchEntry<T>], coefficients: &[u64]) -> bool {
+            let paired = entries.iter().zip(coefficients.iter());
+
+            let key_payloads = paired.clone().filter_map(|((_, payload, kind), r_i)| {
+                matches!(kind, ProofBatchKind::ValidatorKey).then(|| (payload.as_slice(), *r_i))
+            });
+            let combined_keys = Self::combine_payloads(key_payloads);
+
+            let proof_payloads = paired.filter_map(|((_, payload, kind), r_i)| {
+                matches!(kind, ProofBatchKind::ActorXProof(_)).then(|| (payload.as_slice(), *r_i))
+            });
+            let combined_proofs = Self::combine_payloads(proof_payloads);
+
+            (combined_keys.is_empty() || Self::verify_quantum_key(&combined_keys).is_ok())
+                && (combined_proofs.is_empty() || Self::verify_quantum_proof(&combined_proofs).is_ok())
+        }
+
+        /// Slash `fraction` of `validator`'s reserved restake, routing the
+        /// resulting imbalance to `T::Slash` and recording a `SlashRecord`.
+        /// If there's no active restake, `unbond` may still have `amount`
+        /// parked in `Unlocking` (reserved, but no longer counted as active
+        /// restake) awaiting `RestakePeriod` to mature: slash that instead,
+        /// so unbonding can't be used to dodge a pending slash before the
+        /// funds are actually withdrawable. Only a validator with nothing
+        /// reserved at all, active or unlocking, is a genuine no-op.
+        ///
+        /// `pub(crate)` so pallet unit tests can exercise slashing directly,
+        /// without needing a real failing verification to trigger it.
+        pub(crate) fn slash(validator: &T::AccountId, fraction: Perbill, reason: SlashReason) -> DispatchResult {
+            let slashed = if let Some(mut restake) = Self::load_restake(validator)? {
+                let to_slash = fraction * restake.amount;
+                let (imbalance, unslashed) = T::Currency::slash_reserved(validator, to_slash);
+                let slashed = to_slash.saturating_sub(unslashed);
+                T::Slash::on_unbalanced(imbalance);
+
+                restake.amount = restake.amount.saturating_sub(slashed);
+                Self::store_restake(validator, &restake)?;
+                slashed
+            } else {
+                let mut chunks = <Unlocking<T, I>>::get(validator);
+                let total_unlocking: BalanceOf<T, I> =
+                    chunks.iter().fold(Zero::zero(), |acc, chunk| acc.saturating_add(chunk.value));
+                if total_unlocking.is_zero() {
+                    return Ok(());
+                }
+
+                let to_slash = fraction * total_unlocking;
+                let (imbalance, unslashed) = T::Currency::slash_reserved(validator, to_slash);
+                let slashed = to_slash.saturating_sub(unslashed);
+                T::Slash::on_unbalanced(imbalance);
+
+                // Take the slashed amount out of the unlocking chunks,
+                // oldest first, so whatever matures first shrinks first.
+                let mut remaining = slashed;
+                for chunk in chunks.iter_mut() {
+                    let taken = chunk.value.min(remaining);
+                    chunk.value = chunk.value.saturating_sub(taken);
+                    remaining = remaining.saturating_sub(taken);
+                }
+                chunks.retain(|chunk| !chunk.value.is_zero());
+                if chunks.is_empty() {
+                    <Unlocking<T, I>>::remove(validator);
+                } else {
+                    <Unlocking<T, I>>::insert(validator, chunks);
+                }
+                slashed
+            };
+
+            let block = <frame_system::Pallet<T>>::block_number();
+            <SlashRecords<T, I>>::append(
+                validator,
+                SlashRecord { validator: validator.clone(), amount: slashed, block, reason: reason.clone() },
+            );
+
+            Self::deposit_event(Event::ValidatorSlashed(validator.clone(), slashed, reason));
             Ok(())
         }
-        
-        // Apply classical error correction
-        fn apply_classical_error_correction() -> Result<(), ()> {
-            // Reed-Solomon implementation
+
+        /// Remove a validator from the active validator set, if present.
+        fn remove_active_validator(validator: &T::AccountId) {
+            <ActiveValidators<T, I>>::mutate(|active| active.retain(|v| v != validator));
+        }
+
+        /// End the current era: snapshot every active validator's
+        /// restake-weighted points, split `RewardPerEra` pro-rata into
+        /// `PendingRewards`, forfeit the pot to `RewardSource` if nobody was
+        /// active, sweep any reward that has aged out of `HistoryDepth`, and
+        /// advance to the next era.
+        fn rotate_era(now: T::BlockNumber) {
+            let era = <CurrentEra<T, I>>::get();
+            let era_length = T::RestakePeriod::get();
+            let pot = T::RewardPerEra::get();
+
+            let points: Vec<(T::AccountId, BalanceOf<T, I>)> = <ActiveValidators<T, I>>::get()
+                .into_iter()
+                .filter_map(|validator| {
+                    // A payload that fails to decode is treated as having no
+                    // active restake for this era rather than panicking a hook.
+                    Self::load_restake(&validator).ok().flatten().map(|restake| {
+                        let blocks_active = now.saturating_sub(restake.start_block).min(era_length);
+                        let weight = restake
+                            .amount
+                            .saturating_mul(T::BlockNumberToBalance::convert(blocks_active));
+                        (validator, weight)
+                    })
+                })
+                .collect();
+
+            let total_points: BalanceOf<T, I> =
+                points.iter().fold(Zero::zero(), |acc, (_, weight)| acc.saturating_add(*weight));
+
+            if total_points.is_zero() {
+                if !pot.is_zero() {
+                    // Nothing was pre-minted for `pot`: `claim_rewards` is
+                    // the only place a validator's share is actually minted
+                    // (via `deposit_creating`). With nobody active to earn
+                    // it, mint it here instead and hand the imbalance to
+                    // `RewardSource` to dispose of - `burn` would produce a
+                    // `PositiveImbalance`, the wrong polarity for an amount
+                    // that needs to come into existence, not leave it.
+                    T::RewardSource::on_unbalanced(T::Currency::issue(pot));
+                }
+            } else {
+                for (validator, weight) in points.iter() {
+                    let share = pot.saturating_mul(*weight) / total_points;
+                    <PendingRewards<T, I>>::insert(era, validator, share);
+                }
+            }
+
+            <EraRewardPoints<T, I>>::insert(era, points);
+
+            // Sweep whatever is left unclaimed from the era that just aged
+            // out of the claimable window.
+            if let Some(expired_era) = era.checked_sub(T::HistoryDepth::get()) {
+                let forfeited: BalanceOf<T, I> = <PendingRewards<T, I>>::drain_prefix(expired_era)
+                    .fold(Zero::zero(), |acc, (_, amount)| acc.saturating_add(amount));
+                if !forfeited.is_zero() {
+                    // Same reasoning as above: `forfeited` was never minted
+                    // either, so it's `issue`d here, not `burn`t.
+                    T::RewardSource::on_unbalanced(T::Currency::issue(forfeited));
+                }
+                <EraRewardPoints<T, I>>::remove(expired_era);
+            }
+
+            <CurrentEra<T, I>>::put(era.saturating_add(1));
+            <CurrentEraStart<T, I>>::put(now);
+
+            Self::deposit_event(Event::EraRotated(era.saturating_add(1), now));
+        }
+
+        /// Protect `data` before it is written to storage, by running it
+        /// through `ClassicalEcc`, then `BridgeEcc`, then `QuantumEcc`.
+        fn protect(data: Vec<u8>) -> Result<Vec<u8>, Error<T, I>> {
+            let data = T::ClassicalEcc::encode(&data)
+                .map_err(|_| Error::<T, I>::ClassicalErrorCorrectionFailed)?;
+            let data = T::BridgeEcc::encode(&data)
+                .map_err(|_| Error::<T, I>::BridgeErrorCorrectionFailed)?;
+            let data = T::QuantumEcc::encode(&data)
+                .map_err(|_| Error::<T, I>::QuantumErrorCorrectionFailed)?;
+            Ok(data)
+        }
+
+        /// Reverse of [`Self::protect`]: unwinds `QuantumEcc`, then
+        /// `BridgeEcc`, then `ClassicalEcc` to recover the payload that was
+        /// originally protected.
+        fn unprotect(data: &[u8]) -> Result<Vec<u8>, Error<T, I>> {
+            let data = T::QuantumEcc::decode(data)
+                .map_err(|_| Error::<T, I>::QuantumErrorCorrectionFailed)?;
+            let data = T::BridgeEcc::decode(&data)
+                .map_err(|_| Error::<T, I>::BridgeErrorCorrectionFailed)?;
+            let data = T::ClassicalEcc::decode(&data)
+                .map_err(|_| Error::<T, I>::ClassicalErrorCorrectionFailed)?;
+            Ok(data)
+        }
+
+        /// SCALE-encode and protect a `ValidatorInfo`, writing the result to
+        /// `Validators`.
+        fn store_validator(
+            who: &T::AccountId,
+            info: &ValidatorInfo<T::AccountId, T::BlockNumber>,
+        ) -> Result<(), Error<T, I>> {
+            let protected = Self::protect(info.encode())?;
+            <Validators<T, I>>::insert(who, protected);
             Ok(())
         }
-        
-        // Apply bridge error correction
-        fn apply_bridge_error_correction() -> Result<(), ()> {
-            // Bridge error correction implementation
+
+        /// Read `Validators`, unprotect, and SCALE-decode back into a
+        /// `ValidatorInfo`. Returns `Ok(None)` if the account has no entry.
+        fn load_validator(
+            who: &T::AccountId,
+        ) -> Result<Option<ValidatorInfo<T::AccountId, T::BlockNumber>>, Error<T, I>> {
+            let protected = match <Validators<T, I>>::get(who) {
+                Some(protected) => protected,
+                None => return Ok(None),
+            };
+            let raw = Self::unprotect(&protected)?;
+            let info = ValidatorInfo::decode(&mut &raw[..])
+                .map_err(|_| Error::<T, I>::PayloadDecodeFailed)?;
+            Ok(Some(info))
+        }
+
+        /// SCALE-encode and protect a `RestakeInfo`, writing the result to
+        /// `Restakes`.
+        fn store_restake(
+            who: &T::AccountId,
+            info: &RestakeInfo<T::AccountId, BalanceOf<T, I>, T::BlockNumber>,
+        ) -> Result<(), Error<T, I>> {
+            let protected = Self::protect(info.encode())?;
+            <Restakes<T, I>>::insert(who, protected);
             Ok(())
         }
-        
-        // Apply quantum error correction
-        fn apply_quantum_error_correction() -> Result<(), ()> {
-            // Surface codes implementation
+
+        /// Read `Restakes`, unprotect, and SCALE-decode back into a
+        /// `RestakeInfo`. Returns `Ok(None)` if the account has no entry.
+        fn load_restake(
+            who: &T::AccountId,
+        ) -> Result<Option<RestakeInfo<T::AccountId, BalanceOf<T, I>, T::BlockNumber>>, Error<T, I>> {
+            let protected = match <Restakes<T, I>>::get(who) {
+                Some(protected) => protected,
+                None => return Ok(None),
+            };
+            let raw = Self::unprotect(&protected)?;
+            let info = RestakeInfo::decode(&mut &raw[..])
+                .map_err(|_| Error::<T, I>::PayloadDecodeFailed)?;
+            Ok(Some(info))
+        }
+
+        /// SCALE-encode and protect an `ActorXOperation`, writing the result
+        /// to `ActorXOperations`.
+        fn store_actorx_operation(
+            id: OperationId,
+            operation: &ActorXOperation<T::AccountId, T::BlockNumber>,
+        ) -> Result<(), Error<T, I>> {
+            let protected = Self::protect(operation.encode())?;
+            <ActorXOperations<T, I>>::insert(id, protected);
             Ok(())
         }
+
+        /// Read `ActorXOperations`, unprotect, and SCALE-decode back into an
+        /// `ActorXOperation`. Returns `Ok(None)` if `id` is unknown.
+        fn load_actorx_operation(
+            id: OperationId,
+        ) -> Result<Option<ActorXOperation<T::AccountId, T::BlockNumber>>, Error<T, I>> {
+            let protected = match <ActorXOperations<T, I>>::get(id) {
+                Some(protected) => protected,
+                None => return Ok(None),
+            };
+            let raw = Self::unprotect(&protected)?;
+            let operation = ActorXOperation::decode(&mut &raw[..])
+                .map_err(|_| Error::<T, I>::PayloadDecodeFailed)?;
+            Ok(Some(operation))
+        }
+    }
+
+    // Public accessors. `Validators`/`Restakes`/`ActorXOperations` hold
+    // error-correction-protected, SCALE-encoded bytes rather than the
+    // structured types they represent, so a caller reading them directly
+    // would only ever see opaque `Vec<u8>`; these decode that back for them.
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Get the current active validator set.
+        pub fn get_validator_set() -> Vec<T::AccountId> {
+            <ActiveValidators<T, I>>::get()
+        }
+
+        /// Get a validator's registration info, or `None` if it isn't
+        /// registered (or its stored bytes fail to decode).
+        pub fn get_validator_info(who: &T::AccountId) -> Option<ValidatorInfo<T::AccountId, T::BlockNumber>> {
+            Self::load_validator(who).ok().flatten()
+        }
+
+        /// Get information about a validator's restaked tokens, or `None` if
+        /// it has no active restake (or its stored bytes fail to decode).
+        pub fn get_restake_info(
+            who: &T::AccountId,
+        ) -> Option<RestakeInfo<T::AccountId, BalanceOf<T, I>, T::BlockNumber>> {
+            Self::load_restake(who).ok().flatten()
+        }
+
+        /// Get a recorded ActorX operation, or `None` if `id` is unknown (or
+        /// its stored bytes fail to decode).
+        pub fn get_actorx_operation(id: OperationId) -> Option<ActorXOperation<T::AccountId, T::BlockNumber>> {
+            Self::load_actorx_operation(id).ok().flatten()
+        }
+    }
+}
+
+/// Error returned by an [`ErrorCorrection`] layer's `encode`/`decode`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum CorrectionError {
+    /// The input could not be encoded by this layer
+    Encode,
+    /// The input could not be decoded by this layer
+    Decode,
+    /// The input decoded, but failed an internal integrity check
+    Corrupt,
+}
+
+/// A pluggable error-correction layer, wired up per-instance via
+/// `Config::ClassicalEcc`/`BridgeEcc`/`QuantumEcc`. On write, a payload is
+/// run through `encode` on each layer in turn (classical, then bridge, then
+/// quantum); on read it is run back through `decode` in reverse order.
+pub trait ErrorCorrection {
+    /// Protect `data`, returning the bytes to pass to the next layer (or to
+    /// persist, if this is the last layer).
+    fn encode(data: &[u8]) -> Result<Vec<u8>, CorrectionError>;
+    /// Reverse of `encode`: recover the bytes that were passed in.
+    fn decode(data: &[u8]) -> Result<Vec<u8>, CorrectionError>;
+}
+
+/// An [`ErrorCorrection`] layer that passes data through unchanged. A
+/// reasonable default for `BridgeEcc`/`QuantumEcc` in runtimes that do not
+/// yet have a real codec for that layer.
+pub struct NoOpEcc;
+
+impl ErrorCorrection for NoOpEcc {
+    fn encode(data: &[u8]) -> Result<Vec<u8>, CorrectionError> {
+        Ok(data.to_vec())
+    }
+
+    fn decode(data: &[u8]) -> Result<Vec<u8>, CorrectionError> {
+        Ok(data.to_vec())
+    }
+}
+
+/// Size of the Galois field, GF(2^8), that [`ReedSolomon`] does its
+/// arithmetic over.
+const GF_FIELD_SIZE: usize = 256;
+
+/// Primitive polynomial for GF(2^8): x^8 + x^4 + x^3 + x^2 + 1, the same
+/// field generator used by most practical Reed-Solomon codes.
+const GF_PRIMITIVE_POLY: u16 = 0x11D;
+
+/// Build the `exp`/`log` tables for GF(2^8) arithmetic under
+/// `GF_PRIMITIVE_POLY`, generated from `2` (the polynomial `x`).
+fn gf_tables() -> ([u8; GF_FIELD_SIZE], [u8; GF_FIELD_SIZE]) {
+    let mut exp = [0u8; GF_FIELD_SIZE];
+    let mut log = [0u8; GF_FIELD_SIZE];
+    let mut x: u16 = 1;
+    for i in 0..255usize {
+        exp[i] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= GF_PRIMITIVE_POLY;
+        }
+    }
+    exp[255] = exp[0];
+    (exp, log)
+}
+
+fn gf_mul(exp: &[u8; GF_FIELD_SIZE], log: &[u8; GF_FIELD_SIZE], a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        let sum = log[a as usize] as u16 + log[b as usize] as u16;
+        exp[(sum % 255) as usize]
+    }
+}
+
+fn gf_inv(exp: &[u8; GF_FIELD_SIZE], log: &[u8; GF_FIELD_SIZE], a: u8) -> u8 {
+    exp[((255 - log[a as usize] as u16) % 255) as usize]
+}
+
+/// Invert a `k x k` matrix over GF(2^8) via Gauss-Jordan elimination,
+/// augmenting with the identity matrix. Returns `None` if the matrix is
+/// singular.
+fn gf_invert_matrix(
+    exp: &[u8; GF_FIELD_SIZE],
+    log: &[u8; GF_FIELD_SIZE],
+    matrix: &[Vec<u8>],
+) -> Option<Vec<Vec<u8>>> {
+    let k = matrix.len();
+    let mut aug: Vec<Vec<u8>> = matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut row = row.clone();
+            row.extend((0..k).map(|j| if i == j { 1 } else { 0 }));
+            row
+        })
+        .collect();
+
+    for col in 0..k {
+        let pivot_row = (col..k).find(|&r| aug[r][col] != 0)?;
+        aug.swap(col, pivot_row);
+
+        let inv = gf_inv(exp, log, aug[col][col]);
+        for value in aug[col].iter_mut() {
+            *value = gf_mul(exp, log, *value, inv);
+        }
+
+        for row in 0..k {
+            if row == col {
+                continue;
+            }
+            let factor = aug[row][col];
+            if factor == 0 {
+                continue;
+            }
+            for c in 0..aug[row].len() {
+                aug[row][c] ^= gf_mul(exp, log, factor, aug[col][c]);
+            }
+        }
+    }
+
+    Some(aug.into_iter().map(|row| row[k..].to_vec()).collect())
+}
+
+/// Systematic Reed-Solomon error correction over GF(2^8): the payload is
+/// split into `K` data shards, protected by `M` parity shards, recoverable
+/// from any `K` of the resulting `K + M` shards. `K` and `M` are wired up by
+/// the runtime, in the same `Get<u32>`-constant style as the pallet's own
+/// `Config` items.
+///
+/// The wire format is a small header (original length, `K`, `M`, and the
+/// per-shard length, each little-endian) followed by the `K` data shards and
+/// then the `M` parity shards, each shard `ceil(len / K)` bytes long and
+/// zero-padded.
+///
+/// `decode` corrects, not just detects: if a single shard's contents were
+/// altered in transit or in storage, it is identified and recovered from the
+/// remaining `K + M - 1` shards before the payload is handed back. It only
+/// reports [`CorrectionError::Corrupt`] once that recovery attempt is
+/// exhausted, i.e. more than one shard is bad. Locating the bad shard takes
+/// `M >= 2`: with `M == 1` a mismatch is detected but not located, and any
+/// altered shard is reported as [`CorrectionError::Corrupt`].
+pub struct ReedSolomon<K, M>(PhantomData<(K, M)>);
+
+impl<K: Get<u32>, M: Get<u32>> ReedSolomon<K, M> {
+    const HEADER_LEN: usize = 4 + 1 + 1 + 4;
+
+    /// The `M x K` systematic parity-generator matrix: row `p`, column `j`
+    /// is the GF(2^8) coefficient that scales data shard `j` into parity
+    /// shard `p`. Built from a Vandermonde matrix normalized so that the
+    /// combined `(K + M) x K` matrix has an identity top block, which is
+    /// what makes the data shards recoverable unmodified from any `K` of the
+    /// `K + M` shards.
+    fn generator_matrix(exp: &[u8; GF_FIELD_SIZE], log: &[u8; GF_FIELD_SIZE], k: usize, m: usize) -> Vec<Vec<u8>> {
+        // Row i is [x_i^0, x_i^1, ..., x_i^(k-1)] for distinct nonzero x_i.
+        let vandermonde_row = |x: u8| -> Vec<u8> {
+            let mut row = Vec::with_capacity(k);
+            let mut power = 1u8;
+            for _ in 0..k {
+                row.push(power);
+                power = gf_mul(exp, log, power, x);
+            }
+            row
+        };
+
+        let top: Vec<Vec<u8>> = (1..=k as u8).map(vandermonde_row).collect();
+        let bottom: Vec<Vec<u8>> = (k as u8 + 1..=(k + m) as u8).map(vandermonde_row).collect();
+
+        // Normalizing by `top^-1` turns the top block into the identity,
+        // carrying the rest of the matrix into systematic form.
+        let top_inv = gf_invert_matrix(exp, log, &top)
+            .expect("Vandermonde rows are distinct and therefore invertible");
+
+        bottom
+            .iter()
+            .map(|row| {
+                (0..k)
+                    .map(|col| {
+                        (0..k).fold(0u8, |acc, i| acc ^ gf_mul(exp, log, row[i], top_inv[i][col]))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Recover the `K` data shards given any `K` of the `K + M` shards, even
+    /// if the others are missing entirely rather than merely corrupted.
+    /// `available` pairs each present shard with its index (`0..K` for data
+    /// shards, `K..K+M` for parity shards). Used by `decode` to recover from
+    /// a single corrupted shard (by excluding it and reconstructing from the
+    /// rest), and usable directly by a caller holding a partial shard set,
+    /// e.g. a bridge relay that only forwarded some of them.
+    pub fn reconstruct(
+        exp: &[u8; GF_FIELD_SIZE],
+        log: &[u8; GF_FIELD_SIZE],
+        k: usize,
+        generator: &[Vec<u8>],
+        available: &[(usize, Vec<u8>)],
+    ) -> Result<Vec<Vec<u8>>, CorrectionError> {
+        if available.len() < k {
+            return Err(CorrectionError::Decode);
+        }
+
+        // Row `i` of the full systematic matrix: identity for `i < k`, the
+        // corresponding `generator` row for `i >= k`.
+        let full_row = |i: usize| -> Vec<u8> {
+            if i < k {
+                (0..k).map(|j| if i == j { 1 } else { 0 }).collect()
+            } else {
+                generator[i - k].clone()
+            }
+        };
+
+        let chosen = &available[..k];
+        let matrix: Vec<Vec<u8>> = chosen.iter().map(|(i, _)| full_row(*i)).collect();
+        let inverse = gf_invert_matrix(exp, log, &matrix).ok_or(CorrectionError::Decode)?;
+
+        let shard_len = chosen[0].1.len();
+        let mut data_shards = vec![vec![0u8; shard_len]; k];
+        for byte in 0..shard_len {
+            let column: Vec<u8> = chosen.iter().map(|(_, shard)| shard[byte]).collect();
+            for (row, data_shard) in data_shards.iter_mut().enumerate() {
+                data_shard[byte] = (0..k).fold(0u8, |acc, col| {
+                    acc ^ gf_mul(exp, log, inverse[row][col], column[col])
+                });
+            }
+        }
+        Ok(data_shards)
+    }
+
+    /// Recompute the `M` parity shards for `data_shards` under `generator`.
+    /// Shared by `encode` (to produce them) and `decode` (to cross-check
+    /// them against what was stored).
+    fn compute_parity(
+        exp: &[u8; GF_FIELD_SIZE],
+        log: &[u8; GF_FIELD_SIZE],
+        generator: &[Vec<u8>],
+        data_shards: &[Vec<u8>],
+        shard_len: usize,
+    ) -> Vec<Vec<u8>> {
+        generator
+            .iter()
+            .map(|row| {
+                (0..shard_len)
+                    .map(|byte| {
+                        (0..data_shards.len())
+                            .fold(0u8, |acc, j| acc ^ gf_mul(exp, log, row[j], data_shards[j][byte]))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// `true` if every parity shard recomputed from `data_shards` matches
+    /// the corresponding entry in `parity_shards`.
+    fn parity_matches(
+        exp: &[u8; GF_FIELD_SIZE],
+        log: &[u8; GF_FIELD_SIZE],
+        generator: &[Vec<u8>],
+        data_shards: &[Vec<u8>],
+        parity_shards: &[Vec<u8>],
+        shard_len: usize,
+    ) -> bool {
+        Self::compute_parity(exp, log, generator, data_shards, shard_len) == parity_shards
+    }
+}
+
+impl<K: Get<u32>, M: Get<u32>> ErrorCorrection for ReedSolomon<K, M> {
+    fn encode(data: &[u8]) -> Result<Vec<u8>, CorrectionError> {
+        let k = K::get() as usize;
+        let m = M::get() as usize;
+        if k == 0 || m == 0 {
+            return Err(CorrectionError::Encode);
+        }
+
+        let (exp, log) = gf_tables();
+        let shard_len = (data.len() + k - 1) / k.max(1);
+        let shard_len = shard_len.max(1);
+
+        let mut data_shards: Vec<Vec<u8>> = Vec::with_capacity(k);
+        for i in 0..k {
+            let start = i * shard_len;
+            let mut shard = vec![0u8; shard_len];
+            let available = data.len().saturating_sub(start).min(shard_len);
+            shard[..available].copy_from_slice(&data[start..start + available]);
+            data_shards.push(shard);
+        }
+
+        let generator = Self::generator_matrix(&exp, &log, k, m);
+        let parity_shards = Self::compute_parity(&exp, &log, &generator, &data_shards, shard_len);
+
+        let mut out = Vec::with_capacity(Self::HEADER_LEN + (k + m) * shard_len);
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.push(k as u8);
+        out.push(m as u8);
+        out.extend_from_slice(&(shard_len as u32).to_le_bytes());
+        for shard in data_shards.iter().chain(parity_shards.iter()) {
+            out.extend_from_slice(shard);
+        }
+        Ok(out)
+    }
+
+    fn decode(data: &[u8]) -> Result<Vec<u8>, CorrectionError> {
+        if data.len() < Self::HEADER_LEN {
+            return Err(CorrectionError::Decode);
+        }
+
+        let orig_len = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
+        let k = data[4] as usize;
+        let m = data[5] as usize;
+        let shard_len = u32::from_le_bytes([data[6], data[7], data[8], data[9]]) as usize;
+        if k == 0 || data.len() != Self::HEADER_LEN + (k + m) * shard_len {
+            return Err(CorrectionError::Decode);
+        }
+
+        let shard_at = |i: usize| -> Vec<u8> {
+            let start = Self::HEADER_LEN + i * shard_len;
+            data[start..start + shard_len].to_vec()
+        };
+        let shards: Vec<Vec<u8>> = (0..k + m).map(shard_at).collect();
+
+        let (exp, log) = gf_tables();
+        let generator = Self::generator_matrix(&exp, &log, k, m);
+
+        // Recompute parity from the data shards and cross-check it against
+        // what was stored, so corruption in either half is caught rather
+        // than silently returning a mismatched payload.
+        if Self::parity_matches(&exp, &log, &generator, &shards[..k], &shards[k..], shard_len) {
+            let mut out: Vec<u8> = shards[..k].concat();
+            out.truncate(orig_len);
+            return Ok(out);
+        }
+
+        // Parity didn't match, which only tells us *one* of the `k + m`
+        // shards is bad, not which. Try excluding each shard in turn,
+        // reconstruct the codeword from the remaining `k + m - 1`, and
+        // accept the first exclusion whose reconstruction reproduces every
+        // other stored shard exactly - that is the corrupted one, and the
+        // reconstruction recovered the original data without it. With a
+        // single parity shard every exclusion reproduces the rest, so the
+        // bad shard can't be told apart.
+        if m < 2 {
+            return Err(CorrectionError::Corrupt);
+        }
+        for excluded in 0..k + m {
+            let available: Vec<(usize, Vec<u8>)> = (0..k + m)
+                .filter(|&i| i != excluded)
+                .map(|i| (i, shards[i].clone()))
+                .collect();
+
+            let recovered_data = match Self::reconstruct(&exp, &log, k, &generator, &available) {
+                Ok(data_shards) => data_shards,
+                Err(_) => continue,
+            };
+            let recovered_parity = Self::compute_parity(&exp, &log, &generator, &recovered_data, shard_len);
+            let recovered: Vec<&Vec<u8>> = recovered_data.iter().chain(recovered_parity.iter()).collect();
+
+            let matches_others = (0..k + m)
+                .filter(|&i| i != excluded)
+                .all(|i| *recovered[i] == shards[i]);
+            if matches_others {
+                let mut out: Vec<u8> = recovered_data.concat();
+                out.truncate(orig_len);
+                return Ok(out);
+            }
+        }
+
+        Err(CorrectionError::Corrupt)
     }
 }
 
@@ -361,6 +1381,9 @@ pub trait WeightInfo {
     fn restake() -> Weight;
     fn execute_actorx() -> Weight;
     fn verify_validator() -> Weight;
+    fn unbond() -> Weight;
+    fn withdraw_unbonded() -> Weight;
+    fn claim_rewards() -> Weight;
 }
 
 // Type definitions
@@ -369,34 +1392,37 @@ pub type QuantumKey = Vec<u8>;
 pub type QuantumKeyHash = Vec<u8>;
 pub type QuantumProof = Vec<u8>;
 pub type QuantumProofHash = Vec<u8>;
-type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type BalanceOf<T, I = ()> =
+    <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type NegativeImbalanceOf<T, I = ()> =
+    <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
 // Define the validator information struct
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub struct ValidatorInfo<T: Config> {
-    pub account_id: T::AccountId,
+pub struct ValidatorInfo<AccountId, BlockNumber> {
+    pub account_id: AccountId,
     pub quantum_key_hash: QuantumKeyHash,
-    pub registered_at: T::BlockNumber,
+    pub registered_at: BlockNumber,
     pub status: ValidatorStatus,
 }
 
 // Define the restake information struct
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub struct RestakeInfo<T: Config> {
-    pub account_id: T::AccountId,
-    pub amount: BalanceOf<T>,
-    pub start_block: T::BlockNumber,
-    pub unlock_block: T::BlockNumber,
+pub struct RestakeInfo<AccountId, Balance, BlockNumber> {
+    pub account_id: AccountId,
+    pub amount: Balance,
+    pub start_block: BlockNumber,
+    pub unlock_block: BlockNumber,
 }
 
 // Define the ActorX operation struct
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub struct ActorXOperation<T: Config> {
+pub struct ActorXOperation<AccountId, BlockNumber> {
     pub id: OperationId,
     pub operation_type: OperationType,
-    pub executor: T::AccountId,
-    pub target: T::AccountId,
-    pub executed_at: T::BlockNumber,
+    pub executor: AccountId,
+    pub target: AccountId,
+    pub executed_at: BlockNumber,
     pub proof_hash: QuantumProofHash,
 }
 
@@ -414,3 +1440,59 @@ pub enum OperationType {
     Fill,
     Kill,
 }
+
+// Define the quantum verification strategy
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum VerifyStrategy {
+    /// Verify this entry on its own, immediately, aborting the call that
+    /// requested it if verification fails.
+    Individual,
+    /// Record this entry optimistically and queue it into `PendingProofs`.
+    /// Every `Bulk` entry queued so far in the block is settled together,
+    /// once, at `on_finalize`, with a single random-coefficient aggregate
+    /// check; an entry that turns out to be invalid is undone there rather
+    /// than failing the original call.
+    Bulk,
+}
+
+impl Default for VerifyStrategy {
+    fn default() -> Self {
+        VerifyStrategy::Individual
+    }
+}
+
+/// What a [`Pallet::settle_pending_proofs`] entry is for, so a batch
+/// failure at `on_finalize` knows what optimistic write to undo.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ProofBatchKind {
+    /// The payload is the quantum key presented at validator registration
+    ValidatorKey,
+    /// The payload is the proof presented to justify the named ActorX
+    /// operation
+    ActorXProof(OperationId),
+}
+
+// Define the reason a validator was slashed
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum SlashReason {
+    /// A `verify_validator` call resulted in a `Failed` status
+    VerificationFailure,
+    /// An `execute_actorx` call presented a proof that failed verification
+    InvalidOperation,
+}
+
+// Define a single record of a slashing event
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SlashRecord<AccountId, Balance, BlockNumber> {
+    pub validator: AccountId,
+    pub amount: Balance,
+    pub block: BlockNumber,
+    pub reason: SlashReason,
+}
+
+// Define a single unbonding chunk of restake awaiting withdrawal
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct UnlockChunk<Balance, BlockNumber> {
+    pub value: Balance,
+    pub unlock_block: BlockNumber,
+}
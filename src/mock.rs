@@ -0,0 +1,180 @@
+//! Mock runtime for pallet unit tests.
+
+use crate as pallet_eigenlayer;
+use frame_support::{parameter_types, traits::Everything, weights::Weight};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, Convert, IdentityLookup},
+    Perbill,
+};
+
+pub use frame_support::instances::Instance1;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Two instances wired up to exercise that the pallet is genuinely
+// per-instance: `Eigenlayer` stands in for one chain's validator set
+// (e.g. ELXR), `EigenlayerNrsh` for another (NRSH), each with its own
+// storage and no visibility into the other's.
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Eigenlayer: pallet_eigenlayer::{Pallet, Call, Storage, Event<T>},
+        EigenlayerNrsh: pallet_eigenlayer::<Instance1>::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+    pub const MaxLocks: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = MaxLocks;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type Event = Event;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const RestakePeriod: u64 = 10;
+    pub const MinRestakeAmount: u64 = 10;
+    pub const SlashFractionOnVerificationFailure: Perbill = Perbill::from_percent(10);
+    pub const SlashFractionOnInvalidOperation: Perbill = Perbill::from_percent(10);
+    pub const MaxUnlockingChunks: u32 = 8;
+    pub const RewardPerEra: u64 = 100;
+    pub const HistoryDepth: u32 = 4;
+    // Small enough that every shard fits in a few bytes, which keeps the
+    // Reed-Solomon tests cheap while still exercising `K > 1`, `M > 1`.
+    pub const ClassicalShards: u32 = 4;
+    pub const ClassicalParity: u32 = 2;
+}
+
+/// `BlockNumberToBalance` for the mock: blocks and balance share the same
+/// underlying `u64`, so this is a direct cast.
+pub struct BlockNumberToBalanceConverter;
+impl Convert<u64, u64> for BlockNumberToBalanceConverter {
+    fn convert(block_number: u64) -> u64 {
+        block_number
+    }
+}
+
+impl pallet_eigenlayer::Config for Test {
+    type Event = Event;
+    type Currency = Balances;
+    type RestakePeriod = RestakePeriod;
+    type MinRestakeAmount = MinRestakeAmount;
+    type Slash = ();
+    type SlashFractionOnVerificationFailure = SlashFractionOnVerificationFailure;
+    type SlashFractionOnInvalidOperation = SlashFractionOnInvalidOperation;
+    type MaxUnlockingChunks = MaxUnlockingChunks;
+    type RewardPerEra = RewardPerEra;
+    type BlockNumberToBalance = BlockNumberToBalanceConverter;
+    type RewardSource = ();
+    type HistoryDepth = HistoryDepth;
+    type ClassicalEcc = pallet_eigenlayer::ReedSolomon<ClassicalShards, ClassicalParity>;
+    type BridgeEcc = pallet_eigenlayer::NoOpEcc;
+    type QuantumEcc = pallet_eigenlayer::NoOpEcc;
+    type WeightInfo = ();
+}
+
+impl pallet_eigenlayer::Config<Instance1> for Test {
+    type Event = Event;
+    type Currency = Balances;
+    type RestakePeriod = RestakePeriod;
+    type MinRestakeAmount = MinRestakeAmount;
+    type Slash = ();
+    type SlashFractionOnVerificationFailure = SlashFractionOnVerificationFailure;
+    type SlashFractionOnInvalidOperation = SlashFractionOnInvalidOperation;
+    type MaxUnlockingChunks = MaxUnlockingChunks;
+    type RewardPerEra = RewardPerEra;
+    type BlockNumberToBalance = BlockNumberToBalanceConverter;
+    type RewardSource = ();
+    type HistoryDepth = HistoryDepth;
+    type ClassicalEcc = pallet_eigenlayer::ReedSolomon<ClassicalShards, ClassicalParity>;
+    type BridgeEcc = pallet_eigenlayer::NoOpEcc;
+    type QuantumEcc = pallet_eigenlayer::NoOpEcc;
+    type WeightInfo = ();
+}
+
+impl pallet_eigenlayer::WeightInfo for () {
+    fn register_validator() -> Weight {
+        0
+    }
+    fn restake() -> Weight {
+        0
+    }
+    fn execute_actorx() -> Weight {
+        0
+    }
+    fn verify_validator() -> Weight {
+        0
+    }
+    fn unbond() -> Weight {
+        0
+    }
+    fn withdraw_unbonded() -> Weight {
+        0
+    }
+    fn claim_rewards() -> Weight {
+        0
+    }
+}
+
+/// Build a fresh block-1 test externality with a few funded accounts.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)],
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,227 @@
+//! Unit tests for the Eigenlayer pallet and its Reed-Solomon codec.
+
+use crate::mock::*;
+use crate::{CorrectionError, Error, ErrorCorrection, ReedSolomon, SlashReason, Validators, VerifyStrategy};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, Hooks, ReservableCurrency},
+};
+use sp_runtime::Perbill;
+
+#[test]
+fn bulk_strategy_defers_verification_until_on_finalize() {
+    new_test_ext().execute_with(|| {
+        assert!(Eigenlayer::pending_proofs().is_empty());
+
+        assert_ok!(Eigenlayer::register_validator(
+            Origin::signed(1),
+            vec![1, 2, 3],
+            VerifyStrategy::Bulk,
+        ));
+        assert_eq!(
+            Eigenlayer::pending_proofs().len(),
+            1,
+            "a Bulk registration should queue into PendingProofs rather than settle inline",
+        );
+
+        assert_ok!(Eigenlayer::register_validator(
+            Origin::signed(2),
+            vec![4, 5, 6],
+            VerifyStrategy::Bulk,
+        ));
+        assert_eq!(
+            Eigenlayer::pending_proofs().len(),
+            2,
+            "a second Bulk call in the same block should accumulate, not settle immediately",
+        );
+
+        // Both registrations proceed optimistically ahead of settlement.
+        assert!(Validators::<Test>::contains_key(1));
+        assert!(Validators::<Test>::contains_key(2));
+
+        Eigenlayer::on_finalize(1);
+
+        assert!(
+            Eigenlayer::pending_proofs().is_empty(),
+            "on_finalize should drain the queue it just settled",
+        );
+        assert!(Validators::<Test>::contains_key(1));
+        assert!(Validators::<Test>::contains_key(2));
+    });
+}
+
+#[test]
+fn slash_reduces_restake_and_records_it() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Eigenlayer::register_validator(Origin::signed(1), vec![1, 2, 3], VerifyStrategy::Individual));
+        assert_ok!(Eigenlayer::restake(Origin::signed(1), 100, 20));
+        assert_ok!(Eigenlayer::verify_validator(Origin::signed(1), 1));
+        assert!(Eigenlayer::get_validator_set().contains(&1));
+        assert_eq!(Balances::reserved_balance(1), 100);
+
+        assert_ok!(Eigenlayer::slash(&1, Perbill::from_percent(50), SlashReason::InvalidOperation));
+
+        assert_eq!(
+            Eigenlayer::get_restake_info(&1).map(|restake| restake.amount),
+            Some(50),
+            "half the restake should have been slashed away",
+        );
+        assert_eq!(Balances::reserved_balance(1), 50);
+        assert_eq!(Eigenlayer::slash_records(1).len(), 1);
+        assert_eq!(Eigenlayer::slash_records(1)[0].amount, 50);
+    });
+}
+
+#[test]
+fn slash_draws_from_unlocking_once_restake_is_fully_unbonded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Eigenlayer::register_validator(Origin::signed(1), vec![1, 2, 3], VerifyStrategy::Individual));
+        assert_ok!(Eigenlayer::restake(Origin::signed(1), 100, 20));
+        assert_ok!(Eigenlayer::unbond(Origin::signed(1), 100));
+
+        // The restake is fully unbonded (and its Restakes entry gone), but
+        // the funds are still reserved until withdraw_unbonded matures.
+        assert!(Eigenlayer::get_restake_info(&1).is_none());
+        assert_eq!(Balances::reserved_balance(1), 100);
+
+        assert_ok!(Eigenlayer::slash(&1, Perbill::from_percent(50), SlashReason::InvalidOperation));
+
+        assert_eq!(
+            Balances::reserved_balance(1),
+            50,
+            "slashing must still reach funds parked in Unlocking, not no-op once Restakes is empty",
+        );
+        assert_eq!(Eigenlayer::slash_records(1)[0].amount, 50);
+    });
+}
+
+#[test]
+fn unbond_then_withdraw_respects_the_maturity_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Eigenlayer::register_validator(Origin::signed(1), vec![1], VerifyStrategy::Individual));
+        assert_ok!(Eigenlayer::restake(Origin::signed(1), 100, 20));
+        assert_eq!(Balances::reserved_balance(1), 100);
+
+        assert_ok!(Eigenlayer::unbond(Origin::signed(1), 40));
+        assert_eq!(
+            Eigenlayer::get_restake_info(&1).map(|restake| restake.amount),
+            Some(60),
+            "unbonding only earmarks the amount for withdrawal, it doesn't unreserve it yet",
+        );
+        assert_eq!(
+            Balances::reserved_balance(1),
+            100,
+            "still reserved until withdraw_unbonded matures it",
+        );
+
+        assert_noop!(
+            Eigenlayer::withdraw_unbonded(Origin::signed(1)),
+            Error::<Test>::NothingToWithdraw,
+        );
+
+        System::set_block_number(11);
+        assert_ok!(Eigenlayer::withdraw_unbonded(Origin::signed(1)));
+
+        assert_eq!(Balances::reserved_balance(1), 60);
+        assert_eq!(Balances::free_balance(1), 1_000 - 60);
+        assert!(Eigenlayer::unlocking(1).is_empty());
+    });
+}
+
+#[test]
+fn pallet_instances_are_isolated() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Eigenlayer::register_validator(Origin::signed(1), vec![1], VerifyStrategy::Individual));
+        assert!(Validators::<Test>::contains_key(1));
+        assert!(
+            !Validators::<Test, Instance1>::contains_key(1),
+            "a different pallet instance must not see the default instance's validators",
+        );
+
+        assert_ok!(EigenlayerNrsh::register_validator(Origin::signed(2), vec![2], VerifyStrategy::Individual));
+        assert!(Validators::<Test, Instance1>::contains_key(2));
+        assert!(
+            !Validators::<Test>::contains_key(2),
+            "the default instance must not see the other instance's validators",
+        );
+    });
+}
+
+#[test]
+fn era_rotation_pays_pro_rata_reward_exactly_once() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Eigenlayer::register_validator(Origin::signed(1), vec![1], VerifyStrategy::Individual));
+        assert_ok!(Eigenlayer::restake(Origin::signed(1), 100, 20));
+        assert_ok!(Eigenlayer::verify_validator(Origin::signed(1), 1));
+        assert!(Eigenlayer::get_validator_set().contains(&1));
+        assert_eq!(Eigenlayer::current_era(), 0);
+
+        // Advance past RestakePeriod (10 blocks) so on_initialize rotates the era.
+        System::set_block_number(11);
+        Eigenlayer::on_initialize(11);
+
+        assert_eq!(
+            Eigenlayer::current_era(),
+            1,
+            "a full RestakePeriod should have elapsed, rotating era 0 into era 1",
+        );
+        assert_eq!(
+            Eigenlayer::pending_rewards(0, 1),
+            Some(RewardPerEra::get()),
+            "the sole active validator should take the whole era-0 pot pro rata",
+        );
+
+        let before = Balances::free_balance(1);
+        assert_ok!(Eigenlayer::claim_rewards(Origin::signed(1), 0));
+        assert_eq!(Balances::free_balance(1), before + RewardPerEra::get());
+
+        // A claimed era's reward entry is taken, so claiming it twice must fail.
+        assert_noop!(
+            Eigenlayer::claim_rewards(Origin::signed(1), 0),
+            Error::<Test>::NoRewardsForEra,
+        );
+    });
+}
+
+const HEADER_LEN: usize = 4 + 1 + 1 + 4;
+
+fn sample_payload() -> Vec<u8> {
+    (0..37u8).collect()
+}
+
+#[test]
+fn reed_solomon_round_trips() {
+    let payload = sample_payload();
+    let encoded = ReedSolomon::<ClassicalShards, ClassicalParity>::encode(&payload).unwrap();
+    let decoded = ReedSolomon::<ClassicalShards, ClassicalParity>::decode(&encoded).unwrap();
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn reed_solomon_recovers_from_one_corrupted_shard() {
+    let payload = sample_payload();
+    let mut encoded = ReedSolomon::<ClassicalShards, ClassicalParity>::encode(&payload).unwrap();
+
+    // Flip a byte inside the first data shard; decode should still recover
+    // the original payload by excluding and reconstructing it.
+    encoded[HEADER_LEN] ^= 0xFF;
+
+    let decoded = ReedSolomon::<ClassicalShards, ClassicalParity>::decode(&encoded).unwrap();
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn reed_solomon_reports_unrecoverable_corruption() {
+    let payload = sample_payload();
+    let mut encoded = ReedSolomon::<ClassicalShards, ClassicalParity>::encode(&payload).unwrap();
+
+    let shard_len = u32::from_le_bytes([encoded[6], encoded[7], encoded[8], encoded[9]]) as usize;
+    // Corrupt two distinct shards; single-shard recovery can't undo both.
+    encoded[HEADER_LEN] ^= 0xFF;
+    encoded[HEADER_LEN + shard_len] ^= 0xFF;
+
+    assert_eq!(
+        ReedSolomon::<ClassicalShards, ClassicalParity>::decode(&encoded),
+        Err(CorrectionError::Corrupt),
+    );
+}